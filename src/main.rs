@@ -1,5 +1,7 @@
 //! This example demonstrates the built-in 3d shapes in Bevy.
 //! The scene includes a patterned texture and a rotation for visualizing the normals and UVs.
+use std::f32::consts::FRAC_PI_2;
+use noise::{NoiseFn, OpenSimplex};
 use rand::prelude::*;
 use bevy::*;
 use bevy::{
@@ -8,12 +10,13 @@ use bevy::{
 };
 use bevy::prelude::shape::*;
 use bevy::render::mesh::Indices;
+use bevy::window::PrimaryWindow;
 
 fn main() {
     App::new()
         .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest()))
         .add_systems(Startup, setup)
-        .add_systems(Update, rotate)
+        .add_systems(Update, (rotate, pick_shape))
         .run();
 }
 
@@ -54,6 +57,7 @@ fn setup(mut commands: Commands,
                 ..default()
             },
             Shape,
+            shape,
         ));
     }
 
@@ -105,7 +109,12 @@ fn rotate(
     }
 }
 
-#[derive(Debug, Copy, Clone)]
+/// A purely geometric descriptor of a box with rounded edges. Build a mesh
+/// from it via [`SoftBox::mesh`], which returns a [`SoftBoxMeshBuilder`] for
+/// configuring fillet resolution, tangents and UV mapping before `.build()`.
+/// Also usable as a `Component` so picking systems can query the bounds of
+/// the shape an entity was spawned with.
+#[derive(Debug, Copy, Clone, Component)]
 pub struct SoftBox {
     pub min_x: f32,
     pub max_x: f32,
@@ -137,122 +146,643 @@ impl SoftBox {
             edge_radius: edge_radius,
         }
     }
+
+    /// Starts building a configurable mesh for this box, mirroring Bevy's own
+    /// `Shape::mesh()` builder pattern (e.g. `Sphere::new(r).mesh().build()`).
+    pub fn mesh(&self) -> SoftBoxMeshBuilder {
+        SoftBoxMeshBuilder::new(*self)
+    }
+
+    /// Returns the distance along `ray_dir` to the nearest point where the
+    /// ray (`ray_origin`, `ray_dir`, both in world space) enters this box
+    /// (or, when `ray_origin` is already inside it, where the ray exits),
+    /// or `None` if it misses. The ray is transformed into the box's local
+    /// space via `transform`'s inverse before the slab test runs.
+    pub fn ray_intersection(&self, transform: &GlobalTransform, ray_origin: Vec3, ray_dir: Vec3) -> Option<f32> {
+        let inverse = transform.compute_matrix().inverse();
+        let local_origin = inverse.transform_point3(ray_origin);
+        let local_dir = inverse.transform_vector3(ray_dir);
+
+        let mins = [self.min_x, self.min_y, self.min_z];
+        let maxs = [self.max_x, self.max_y, self.max_z];
+        let origins = [local_origin.x, local_origin.y, local_origin.z];
+        let dirs = [local_dir.x, local_dir.y, local_dir.z];
+
+        let mut t_near = f32::NEG_INFINITY;
+        let mut t_far = f32::INFINITY;
+        let mut near_axis = 0usize;
+        let mut far_axis = 0usize;
+        for axis in 0..3 {
+            let (min, max, origin, dir) = (mins[axis], maxs[axis], origins[axis], dirs[axis]);
+            if dir.abs() < f32::EPSILON {
+                if origin < min || origin > max {
+                    return None;
+                }
+                continue;
+            }
+            let t1 = (min - origin) / dir;
+            let t2 = (max - origin) / dir;
+            let (t1, t2) = (t1.min(t2), t1.max(t2));
+            if t1 > t_near {
+                t_near = t1;
+                near_axis = axis;
+            }
+            if t2 < t_far {
+                t_far = t2;
+                far_axis = axis;
+            }
+            if t_near > t_far {
+                return None;
+            }
+        }
+        if t_far < 0.0 {
+            return None;
+        }
+        // When the ray origin starts inside the box (e.g. the camera flew
+        // through it), `t_near` is negative and the actual hit is the exit
+        // face at `t_far` - so `hit_axis` must follow whichever of the two
+        // was actually used, not always the entry face.
+        let (hit_t, hit_axis) = if t_near >= 0.0 { (t_near, near_axis) } else { (t_far, far_axis) };
+
+        // Refine against the rounded-edge radius. `hit_axis` is the face the
+        // flat slab test actually hit through, so its coordinate sits
+        // exactly on `min`/`max` and is never inset - only the other two
+        // axes can land in the rounded region. One of them being inset means
+        // the true surface there is the swept cylinder along the remaining
+        // axis; both being inset means it's the corner's swept sphere.
+        let r = self.edge_radius;
+        let hit_point = local_origin + local_dir * hit_t;
+        let coords = [hit_point.x, hit_point.y, hit_point.z];
+        let signed_inset = |axis: usize| -> f32 {
+            let mid = (mins[axis] + maxs[axis]) / 2.0;
+            if coords[axis] > mid { maxs[axis] - r } else { mins[axis] + r }
+        };
+        let near_axes: Vec<usize> = (0..3)
+            .filter(|&axis| axis != hit_axis)
+            .filter(|&axis| coords[axis] < mins[axis] + r || coords[axis] > maxs[axis] - r)
+            .collect();
+
+        let refined = match near_axes.as_slice() {
+            [] => None,
+            &[edge_axis] => {
+                let free_axis = 3 - hit_axis - edge_axis;
+                let mut center = hit_point;
+                center[hit_axis] = signed_inset(hit_axis);
+                center[edge_axis] = signed_inset(edge_axis);
+                ray_round_surface_intersection(local_origin, local_dir, center, r, Some(free_axis))
+            }
+            _ => {
+                let center = Vec3::new(signed_inset(0), signed_inset(1), signed_inset(2));
+                ray_round_surface_intersection(local_origin, local_dir, center, r, None)
+            }
+        };
+
+        refined.or(Some(hit_t))
+    }
+
+    /// Returns the `(min, max)` corners of this box's axis-aligned bounding
+    /// box, in local space.
+    pub fn aabb(&self) -> (Vec3, Vec3) {
+        (
+            Vec3::new(self.min_x, self.min_y, self.min_z),
+            Vec3::new(self.max_x, self.max_y, self.max_z),
+        )
+    }
+
+    /// Returns the `(center, radius)` of a sphere that tightly bounds this
+    /// box's actual rounded surface. The center is the box's centroid; the
+    /// radius is the distance from the centroid to the box's farthest
+    /// "corner center" (the point the corner fillet is swept around) plus
+    /// `edge_radius`, so the sphere just touches the rounded corner itself
+    /// rather than the unrounded outer corner.
+    pub fn bounding_sphere(&self) -> (Vec3, f32) {
+        let center = Vec3::new(
+            (self.min_x + self.max_x) / 2.0,
+            (self.min_y + self.max_y) / 2.0,
+            (self.min_z + self.max_z) / 2.0,
+        );
+        let corner_center = Vec3::new(
+            self.max_x - self.edge_radius,
+            self.max_y - self.edge_radius,
+            self.max_z - self.edge_radius,
+        );
+        let radius = (corner_center - center).length() + self.edge_radius;
+        (center, radius)
+    }
+}
+
+/// Nearest non-negative `t` where `origin + t * dir` lies on the sphere of
+/// `radius` centered at `center`, or on the infinite cylinder of `radius`
+/// running through `center` parallel to `free_axis` when one is given (used
+/// for edge fillets, where the hit can land anywhere along the edge).
+fn ray_round_surface_intersection(origin: Vec3, dir: Vec3, center: Vec3, radius: f32, free_axis: Option<usize>) -> Option<f32> {
+    let mut oc = origin - center;
+    let mut dir = dir;
+    if let Some(axis) = free_axis {
+        oc[axis] = 0.0;
+        dir[axis] = 0.0;
+    }
+    let a = dir.dot(dir);
+    if a < f32::EPSILON {
+        return None;
+    }
+    let b = 2.0 * oc.dot(dir);
+    let c = oc.dot(oc) - radius * radius;
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let sqrt_discriminant = discriminant.sqrt();
+    let t0 = (-b - sqrt_discriminant) / (2.0 * a);
+    let t1 = (-b + sqrt_discriminant) / (2.0 * a);
+    if t0 >= 0.0 {
+        Some(t0)
+    } else if t1 >= 0.0 {
+        Some(t1)
+    } else {
+        None
+    }
+}
+
+/// Casts a ray from the camera through the cursor each frame and logs the
+/// nearest `Shape` entity it hits, giving the example interactive selection.
+fn pick_shape(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    shapes: Query<(Entity, &GlobalTransform, &SoftBox), With<Shape>>,
+) {
+    let Ok(window) = windows.get_single() else { return; };
+    let Some(cursor_position) = window.cursor_position() else { return; };
+    let Ok((camera, camera_transform)) = cameras.get_single() else { return; };
+    let Some(ray) = camera.viewport_to_world(camera_transform, cursor_position) else { return; };
+
+    let mut closest: Option<(Entity, f32)> = None;
+    for (entity, transform, softbox) in &shapes {
+        if let Some(t) = softbox.ray_intersection(transform, ray.origin, ray.direction) {
+            if closest.map_or(true, |(_, best_t)| t < best_t) {
+                closest = Some((entity, t));
+            }
+        }
+    }
+
+    if let Some((entity, _)) = closest {
+        info!("Picked shape entity {:?}", entity);
+    }
+}
+
+/// Controls how UV coordinates are laid out across a [`SoftBox`]'s flat faces.
+#[derive(Debug, Copy, Clone, PartialEq, Default)]
+pub enum SoftBoxUvMode {
+    /// Each flat face fills the full `0..1` UV range, regardless of its size.
+    #[default]
+    Stretched,
+    /// Each flat face is mapped so that one UV unit covers one world unit,
+    /// keeping texel density consistent across faces of different sizes.
+    Tiled,
+}
+
+/// Builds a [`Mesh`] from a [`SoftBox`], configuring fillet resolution,
+/// tangent generation and UV mapping. Create one via [`SoftBox::mesh`].
+#[derive(Debug, Copy, Clone)]
+pub struct SoftBoxMeshBuilder {
+    pub softbox: SoftBox,
+    /// Number of steps used to tessellate each rounded edge and corner.
+    /// `0` keeps the degenerate behavior of a single flat chamfer
+    /// triangle/strip per edge and corner.
+    pub segments: u32,
+    /// Whether to compute `Mesh::ATTRIBUTE_TANGENT`, required for normal maps.
+    pub generate_tangents: bool,
+    pub uv_mode: SoftBoxUvMode,
+}
+
+impl SoftBoxMeshBuilder {
+    pub fn new(softbox: SoftBox) -> Self {
+        SoftBoxMeshBuilder {
+            softbox,
+            segments: 0,
+            generate_tangents: false,
+            uv_mode: SoftBoxUvMode::default(),
+        }
+    }
+
+    /// Sets the number of steps used to tessellate each rounded edge and corner.
+    pub fn segments(mut self, segments: u32) -> Self {
+        self.segments = segments;
+        self
+    }
+
+    /// Enables generating `Mesh::ATTRIBUTE_TANGENT`, required for normal maps.
+    pub fn generate_tangents(mut self, generate_tangents: bool) -> Self {
+        self.generate_tangents = generate_tangents;
+        self
+    }
+
+    pub fn uv_mode(mut self, uv_mode: SoftBoxUvMode) -> Self {
+        self.uv_mode = uv_mode;
+        self
+    }
+
+    pub fn build(&self) -> Mesh {
+        let mut mesh = if self.segments > 0 {
+            build_filleted_mesh(&self.softbox, self.segments, self.uv_mode)
+        } else {
+            build_chamfered_mesh(&self.softbox, self.uv_mode)
+        };
+        if self.generate_tangents {
+            // Needed for StandardMaterial::normal_map_texture/parallax maps to
+            // render correctly; computed from the UV layout just like Bevy's
+            // own SphereMeshBuilder etc. expect callers to do. Don't swallow
+            // the error: that's exactly the silent-failure mode this request
+            // exists to fix, just moved one level up.
+            if let Err(err) = mesh.generate_tangents() {
+                warn!("SoftBoxMeshBuilder failed to generate tangents: {err}");
+            }
+        }
+        mesh
+    }
+}
+
+/// The growing vertex/index buffers shared by the mesh-construction helpers
+/// below. Bundled into one struct (rather than threading four `&mut Vec<_>`
+/// through every helper) so those helpers stay under clippy's argument-count
+/// lint as more fillet geometry gets added.
+#[derive(Default)]
+struct MeshBuffers {
+    positions: Vec<[f32; 3]>,
+    normals: Vec<[f32; 3]>,
+    uvs: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+}
+
+impl MeshBuffers {
+    /// Pushes a single vertex and returns its index.
+    fn push_vertex(&mut self, position: Vec3, normal: Vec3, uv: [f32; 2]) -> u32 {
+        let index = self.positions.len() as u32;
+        self.positions.push(position.into());
+        self.normals.push(normal.into());
+        self.uvs.push(uv);
+        index
+    }
+
+    /// Emits the two triangles of a quad, flipping the winding order if needed
+    /// so the triangle normal points away from the local origin (the box's
+    /// center for a box built with [`SoftBox::new`]). This keeps
+    /// the fillet-generating code below from having to hand-derive the
+    /// correct winding for every edge/corner sign permutation.
+    fn push_oriented_quad(&mut self, a: u32, b: u32, c: u32, d: u32) {
+        let pa = Vec3::from(self.positions[a as usize]);
+        let pb = Vec3::from(self.positions[b as usize]);
+        let pc = Vec3::from(self.positions[c as usize]);
+        let pd = Vec3::from(self.positions[d as usize]);
+        let center = (pa + pb + pc + pd) / 4.0;
+        let face_normal = (pb - pa).cross(pc - pa);
+        if face_normal.dot(center) >= 0.0 {
+            self.indices.extend_from_slice(&[a, b, c, a, c, d]);
+        } else {
+            self.indices.extend_from_slice(&[a, c, b, a, d, c]);
+        }
+    }
+}
+
+/// Tessellates the quarter-cylinder fillet running along one of the box's 12
+/// edges, between `axis_point0` and `axis_point1` (the edge's two rounded
+/// endpoints), using `dir_fn(theta)` to sweep the radial direction from one
+/// adjacent face normal to the other as `theta` goes from `0` to `PI/2`.
+fn build_edge_arc(
+    buffers: &mut MeshBuffers,
+    axis_point0: Vec3,
+    axis_point1: Vec3,
+    radius: f32,
+    dir_fn: impl Fn(f32) -> Vec3,
+    segments: u32,
+) {
+    let mut ring0 = Vec::with_capacity(segments as usize + 1);
+    let mut ring1 = Vec::with_capacity(segments as usize + 1);
+    for i in 0..=segments {
+        let theta = i as f32 / segments as f32 * FRAC_PI_2;
+        let dir = dir_fn(theta);
+        let u = i as f32 / segments as f32;
+        ring0.push(buffers.push_vertex(axis_point0 + radius * dir, dir, [u, 0.0]));
+        ring1.push(buffers.push_vertex(axis_point1 + radius * dir, dir, [u, 1.0]));
+    }
+    for i in 0..segments as usize {
+        buffers.push_oriented_quad(ring0[i], ring0[i + 1], ring1[i + 1], ring1[i]);
+    }
+}
+
+/// Tessellates the spherical octant fillet at one of the box's 8 corners,
+/// centered at `center`. `sx`/`sy`/`sz` (each `1.0` or `-1.0`) pick which of
+/// the 8 octants of the sphere to generate.
+fn build_corner_patch(buffers: &mut MeshBuffers, center: Vec3, radius: f32, sx: f32, sy: f32, sz: f32, segments: u32) {
+    let mut rings = Vec::with_capacity(segments as usize + 1);
+    for pi in 0..=segments {
+        let phi = pi as f32 / segments as f32 * FRAC_PI_2;
+        let mut row = Vec::with_capacity(segments as usize + 1);
+        for ti in 0..=segments {
+            let theta = ti as f32 / segments as f32 * FRAC_PI_2;
+            let dir = Vec3::new(sx * phi.sin() * theta.cos(), sy * phi.cos(), sz * phi.sin() * theta.sin());
+            let uv = [ti as f32 / segments as f32, pi as f32 / segments as f32];
+            row.push(buffers.push_vertex(center + radius * dir, dir, uv));
+        }
+        rings.push(row);
+    }
+    for pi in 0..segments as usize {
+        for ti in 0..segments as usize {
+            let a = rings[pi][ti];
+            let b = rings[pi][ti + 1];
+            let c = rings[pi + 1][ti + 1];
+            let d = rings[pi + 1][ti];
+            buffers.push_oriented_quad(a, b, c, d);
+        }
+    }
+}
+
+/// Per-vertex `(width, height)` of the flat face each of the 24 face
+/// vertices belongs to, used to scale UVs for [`SoftBoxUvMode::Tiled`].
+fn face_uv_dims(sp: &SoftBox, r: f32) -> [(f32, f32); 24] {
+    let fb = (sp.max_x - sp.min_x - 2.0 * r, sp.max_y - sp.min_y - 2.0 * r);
+    let lr = (sp.max_y - sp.min_y - 2.0 * r, sp.max_z - sp.min_z - 2.0 * r);
+    let tb = (sp.max_x - sp.min_x - 2.0 * r, sp.max_z - sp.min_z - 2.0 * r);
+    [
+        fb, fb, fb, fb, // front
+        fb, fb, fb, fb, // back
+        lr, lr, lr, lr, // right
+        lr, lr, lr, lr, // left
+        tb, tb, tb, tb, // top
+        tb, tb, tb, tb, // bottom
+    ]
+}
+
+fn scale_face_uv(uv: [f32; 2], dims: (f32, f32), uv_mode: SoftBoxUvMode) -> [f32; 2] {
+    match uv_mode {
+        SoftBoxUvMode::Stretched => uv,
+        SoftBoxUvMode::Tiled => [uv[0] * dims.0, uv[1] * dims.1],
+    }
+}
+
+/// The 24 flat-face vertices (position, normal, unscaled UV), inset by `r` on
+/// every side. Shared by [`build_filleted_mesh`] and [`build_chamfered_mesh`],
+/// since the flat faces themselves don't depend on fillet resolution - only
+/// how the rounded edges/corners between them are tessellated does.
+fn flat_face_vertices(sp: &SoftBox, r: f32) -> [([f32; 3], [f32; 3], [f32; 2]); 24] {
+    [
+        // Front
+        ([sp.min_x + r, sp.min_y + r, sp.max_z], [0., 0., 1.0], [0., 0.]),
+        ([sp.max_x - r, sp.min_y + r, sp.max_z], [0., 0., 1.0], [1.0, 0.]),
+        ([sp.max_x - r, sp.max_y - r, sp.max_z], [0., 0., 1.0], [1.0, 1.0]),
+        ([sp.min_x + r, sp.max_y - r, sp.max_z], [0., 0., 1.0], [0., 1.0]),
+        // Back
+        ([sp.min_x + r, sp.max_y - r, sp.min_z], [0., 0., -1.0], [1.0, 0.]),
+        ([sp.max_x - r, sp.max_y - r, sp.min_z], [0., 0., -1.0], [0., 0.]),
+        ([sp.max_x - r, sp.min_y + r, sp.min_z], [0., 0., -1.0], [0., 1.0]),
+        ([sp.min_x + r, sp.min_y + r, sp.min_z], [0., 0., -1.0], [1.0, 1.0]),
+        // Right
+        ([sp.max_x, sp.min_y + r, sp.min_z + r], [1.0, 0., 0.], [0., 0.]),
+        ([sp.max_x, sp.max_y - r, sp.min_z + r], [1.0, 0., 0.], [1.0, 0.]),
+        ([sp.max_x, sp.max_y - r, sp.max_z - r], [1.0, 0., 0.], [1.0, 1.0]),
+        ([sp.max_x, sp.min_y + r, sp.max_z - r], [1.0, 0., 0.], [0., 1.0]),
+        // Left
+        ([sp.min_x, sp.min_y + r, sp.max_z - r], [-1.0, 0., 0.], [1.0, 0.]),
+        ([sp.min_x, sp.max_y - r, sp.max_z - r], [-1.0, 0., 0.], [0., 0.]),
+        ([sp.min_x, sp.max_y - r, sp.min_z + r], [-1.0, 0., 0.], [0., 1.0]),
+        ([sp.min_x, sp.min_y + r, sp.min_z + r], [-1.0, 0., 0.], [1.0, 1.0]),
+        // Top
+        ([sp.max_x - r, sp.max_y, sp.min_z + r], [0., 1.0, 0.], [1.0, 0.]),
+        ([sp.min_x + r, sp.max_y, sp.min_z + r], [0., 1.0, 0.], [0., 0.]),
+        ([sp.min_x + r, sp.max_y, sp.max_z - r], [0., 1.0, 0.], [0., 1.0]),
+        ([sp.max_x - r, sp.max_y, sp.max_z - r], [0., 1.0, 0.], [1.0, 1.0]),
+        // Bottom
+        ([sp.max_x - r, sp.min_y, sp.max_z - r], [0., -1.0, 0.], [0., 0.]),
+        ([sp.min_x + r, sp.min_y, sp.max_z - r], [0., -1.0, 0.], [1.0, 0.]),
+        ([sp.min_x + r, sp.min_y, sp.min_z + r], [0., -1.0, 0.], [1.0, 1.0]),
+        ([sp.max_x - r, sp.min_y, sp.min_z + r], [0., -1.0, 0.], [0., 1.0]),
+    ]
+}
+
+/// Triangle indices for the 6 flat faces generated by [`flat_face_vertices`],
+/// shared by [`build_filleted_mesh`] and [`build_chamfered_mesh`].
+const FLAT_FACE_INDICES: [u32; 36] = [
+    0, 1, 2, 2, 3, 0, // front
+    4, 5, 6, 6, 7, 4, // back
+    8, 9, 10, 10, 11, 8, // right
+    12, 13, 14, 14, 15, 12, // left
+    16, 17, 18, 18, 19, 16, // top
+    20, 21, 22, 22, 23, 20, // bottom
+];
+
+/// Builds a `SoftBox` mesh with genuinely curved fillets: the flat faces are
+/// inset by `edge_radius` as before, each of the 12 edges is a swept
+/// quarter-cylinder, and each of the 8 corners is a tessellated spherical
+/// octant, all sharing the box's inset "corner centers".
+fn build_filleted_mesh(sp: &SoftBox, segments: u32, uv_mode: SoftBoxUvMode) -> Mesh {
+    let r = sp.edge_radius;
+
+    let mut buffers = MeshBuffers::default();
+
+    // Flat faces, inset by `r` on every side - unaffected by fillet resolution.
+    let dims = face_uv_dims(sp, r);
+    for (i, (p, n, uv)) in flat_face_vertices(sp, r).iter().enumerate() {
+        let uv = scale_face_uv(*uv, dims[i], uv_mode);
+        buffers.push_vertex(Vec3::from(*p), Vec3::from(*n), uv);
+    }
+    buffers.indices.extend_from_slice(&FLAT_FACE_INDICES);
+
+    // Corner centers: the box interior inset by `r` on every axis.
+    let corner_center = |sx: f32, sy: f32, sz: f32| {
+        Vec3::new(
+            if sx > 0.0 { sp.max_x - r } else { sp.min_x + r },
+            if sy > 0.0 { sp.max_y - r } else { sp.min_y + r },
+            if sz > 0.0 { sp.max_z - r } else { sp.min_z + r },
+        )
+    };
+
+    // The 12 edges, grouped by the axis they run along.
+    for &sx in &[-1.0f32, 1.0] {
+        for &sy in &[-1.0f32, 1.0] {
+            let c = corner_center(sx, sy, 0.0);
+            build_edge_arc(
+                &mut buffers,
+                Vec3::new(c.x, c.y, sp.min_z + r),
+                Vec3::new(c.x, c.y, sp.max_z - r),
+                r,
+                |theta| Vec3::new(sx * theta.cos(), sy * theta.sin(), 0.0),
+                segments,
+            );
+        }
+    }
+    for &sy in &[-1.0f32, 1.0] {
+        for &sz in &[-1.0f32, 1.0] {
+            let c = corner_center(0.0, sy, sz);
+            build_edge_arc(
+                &mut buffers,
+                Vec3::new(sp.min_x + r, c.y, c.z),
+                Vec3::new(sp.max_x - r, c.y, c.z),
+                r,
+                |theta| Vec3::new(0.0, sy * theta.cos(), sz * theta.sin()),
+                segments,
+            );
+        }
+    }
+    for &sx in &[-1.0f32, 1.0] {
+        for &sz in &[-1.0f32, 1.0] {
+            let c = corner_center(sx, 0.0, sz);
+            build_edge_arc(
+                &mut buffers,
+                Vec3::new(c.x, sp.min_y + r, c.z),
+                Vec3::new(c.x, sp.max_y - r, c.z),
+                r,
+                |theta| Vec3::new(sx * theta.cos(), 0.0, sz * theta.sin()),
+                segments,
+            );
+        }
+    }
+
+    // The 8 corners, each a spherical octant.
+    for &sx in &[-1.0f32, 1.0] {
+        for &sy in &[-1.0f32, 1.0] {
+            for &sz in &[-1.0f32, 1.0] {
+                build_corner_patch(
+                    &mut buffers,
+                    corner_center(sx, sy, sz),
+                    r,
+                    sx, sy, sz,
+                    segments,
+                );
+            }
+        }
+    }
+
+    Mesh::new(PrimitiveTopology::TriangleList)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, buffers.positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, buffers.normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, buffers.uvs)
+        .with_indices(Some(Indices::U32(buffers.indices)))
 }
 
 impl From<SoftBox> for Mesh {
     fn from(sp: SoftBox) -> Self {
-        let _r = sp.edge_radius;
-        let vertices = &[
-            // Front
-            ([sp.min_x + _r, sp.min_y + _r, sp.max_z], [0., 0., 1.0], [0., 0.]),    // 0 - bottom, left
-            ([sp.max_x - _r, sp.min_y + _r, sp.max_z], [0., 0., 1.0], [1.0, 0.]),   // 1 - bottom, right
-            ([sp.max_x - _r, sp.max_y - _r, sp.max_z], [0., 0., 1.0], [1.0, 1.0]),  // 2 - top, right
-            ([sp.min_x + _r, sp.max_y - _r, sp.max_z], [0., 0., 1.0], [0., 1.0]),   // 3 - top, left
-            // Back
-            ([sp.min_x + _r, sp.max_y - _r, sp.min_z], [0., 0., -1.0], [1.0, 0.]),  // 4 - top, left
-            ([sp.max_x - _r, sp.max_y - _r, sp.min_z], [0., 0., -1.0], [0., 0.]),   // 5 - top, right
-            ([sp.max_x - _r, sp.min_y + _r, sp.min_z], [0., 0., -1.0], [0., 1.0]),  // 6 - bottom, right
-            ([sp.min_x + _r, sp.min_y + _r, sp.min_z], [0., 0., -1.0], [1.0, 1.0]), // 7 - bottom, left
-            // Right
-            ([sp.max_x, sp.min_y + _r, sp.min_z + _r], [1.0, 0., 0.], [0., 0.]),    // 8 - bottom, far
-            ([sp.max_x, sp.max_y - _r, sp.min_z + _r], [1.0, 0., 0.], [1.0, 0.]),   // 9 - top, far
-            ([sp.max_x, sp.max_y - _r, sp.max_z - _r], [1.0, 0., 0.], [1.0, 1.0]),  // 10 - top, near
-            ([sp.max_x, sp.min_y + _r, sp.max_z - _r], [1.0, 0., 0.], [0., 1.0]),   // 11 - bottom, near
-            // Left
-            ([sp.min_x, sp.min_y + _r, sp.max_z - _r], [-1.0, 0., 0.], [1.0, 0.]),  // 12 - bottom, near
-            ([sp.min_x, sp.max_y - _r, sp.max_z - _r], [-1.0, 0., 0.], [0., 0.]),   // 13 - top, near
-            ([sp.min_x, sp.max_y - _r, sp.min_z + _r], [-1.0, 0., 0.], [0., 1.0]),  // 14 - top, far
-            ([sp.min_x, sp.min_y + _r, sp.min_z + _r], [-1.0, 0., 0.], [1.0, 1.0]), // 15 - bottom, far
-            // Top
-            ([sp.max_x - _r, sp.max_y, sp.min_z + _r], [0., 1.0, 0.], [1.0, 0.]),   // 16 - right, far
-            ([sp.min_x + _r, sp.max_y, sp.min_z + _r], [0., 1.0, 0.], [0., 0.]),    // 17 - left, far
-            ([sp.min_x + _r, sp.max_y, sp.max_z - _r], [0., 1.0, 0.], [0., 1.0]),   // 18 - left, near
-            ([sp.max_x - _r, sp.max_y, sp.max_z - _r], [0., 1.0, 0.], [1.0, 1.0]),  // 19 - right, near
-            // Bottom
-            ([sp.max_x - _r, sp.min_y, sp.max_z - _r], [0., -1.0, 0.], [0., 0.]),   // 20 - right, near
-            ([sp.min_x + _r, sp.min_y, sp.max_z - _r], [0., -1.0, 0.], [1.0, 0.]),  // 21 - left, near
-            ([sp.min_x + _r, sp.min_y, sp.min_z + _r], [0., -1.0, 0.], [1.0, 1.0]), // 22 - left, far
-            ([sp.max_x - _r, sp.min_y, sp.min_z + _r], [0., -1.0, 0.], [0., 1.0]),  // 23 - right, far
-        ];
-
-        let positions: Vec<_> = vertices.iter().map(|(p, _, _)| *p).collect();
-        let normals: Vec<_> = vertices.iter().map(|(_, n, _)| *n).collect();
-        let uvs: Vec<_> = vertices.iter().map(|(_, _, uv)| *uv).collect();
-
-        let indices = Indices::U32(vec![
-            // faces
-            0, 1, 2, 2, 3, 0, // front
-            4, 5, 6, 6, 7, 4, // back
-            8, 9, 10, 10, 11, 8, // right
-            12, 13, 14, 14, 15, 12, // left
-            16, 17, 18, 18, 19, 16, // top
-            20, 21, 22, 22, 23, 20, // bottom
-
-            // edges
-            0, 3, 13, 13, 12, 0,    // front/left
-            18, 17, 13, 14, 13, 17, // top/left
-            4, 7, 14, 14, 7, 15,    // back/left
-            22, 21, 15, 12, 15, 21, // bottom/left
-
-            2, 1, 10, 1, 11, 10,    // front/right
-            16, 19, 10, 10, 9, 16,  // top/right
-            6, 5, 9, 9, 8, 6,       // back/right
-            20, 23, 8, 8, 11, 20,   // bottom/right
-
-            19, 18, 2, 3, 2, 18,    // top/front
-            1, 0, 20, 21, 20, 0,    // front/bottom
-            23, 22, 7, 7, 6, 23,    // bottom/back
-            5, 4, 17, 17, 16, 5,    // back/top
-
-            // corners
-            18, 13, 3, // top/left/front
-            17, 4, 14, // top/left/back
-            19, 2, 10, // top/right/front
-            16, 9, 5,  // top/right/back
-
-            21, 0, 12, // bottom/left/front
-            22, 15, 7, // bottom/left/back
-            20, 11, 1, // bottom/right/front
-            23, 6, 8,  // bottom/right/back
-        ]);
-
-        Mesh::new(PrimitiveTopology::TriangleList)
-            .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
-            .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
-            .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
-            .with_indices(Some(indices))
+        sp.mesh().build()
     }
 }
 
-fn get_world_points() -> Vec<PointI32> {
+/// Builds a `SoftBox` mesh the old way: each rounded edge and corner is a
+/// single flat chamfer triangle/strip rather than a true fillet. Used when
+/// the builder's `segments` is `0`.
+fn build_chamfered_mesh(sp: &SoftBox, uv_mode: SoftBoxUvMode) -> Mesh {
+    let r = sp.edge_radius;
+    let vertices = flat_face_vertices(sp, r);
+
+    let dims = face_uv_dims(sp, r);
+    let positions: Vec<_> = vertices.iter().map(|(p, _, _)| *p).collect();
+    let normals: Vec<_> = vertices.iter().map(|(_, n, _)| *n).collect();
+    let uvs: Vec<_> = vertices.iter().enumerate().map(|(i, (_, _, uv))| scale_face_uv(*uv, dims[i], uv_mode)).collect();
+
+    let mut indices = FLAT_FACE_INDICES.to_vec();
+    indices.extend_from_slice(&[
+        // edges
+        0, 3, 13, 13, 12, 0,    // front/left
+        18, 17, 13, 14, 13, 17, // top/left
+        4, 7, 14, 14, 7, 15,    // back/left
+        22, 21, 15, 12, 15, 21, // bottom/left
+
+        2, 1, 10, 1, 11, 10,    // front/right
+        16, 19, 10, 10, 9, 16,  // top/right
+        6, 5, 9, 9, 8, 6,       // back/right
+        20, 23, 8, 8, 11, 20,   // bottom/right
+
+        19, 18, 2, 3, 2, 18,    // top/front
+        1, 0, 20, 21, 20, 0,    // front/bottom
+        23, 22, 7, 7, 6, 23,    // bottom/back
+        5, 4, 17, 17, 16, 5,    // back/top
+
+        // corners
+        18, 13, 3, // top/left/front
+        17, 4, 14, // top/left/back
+        19, 2, 10, // top/right/front
+        16, 9, 5,  // top/right/back
+
+        21, 0, 12, // bottom/left/front
+        22, 15, 7, // bottom/left/back
+        20, 11, 1, // bottom/right/front
+        23, 6, 8,  // bottom/right/back
+    ]);
+
+    Mesh::new(PrimitiveTopology::TriangleList)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_POSITION, positions)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_NORMAL, normals)
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, uvs)
+        .with_indices(Some(Indices::U32(indices)))
+}
+
+/// Parameters for the procedural voxel-terrain generator.
+#[derive(Debug, Copy, Clone)]
+pub struct TerrainConfig {
+    /// Side length (in voxels) of the square x/z grid to generate.
+    pub grid_size: i32,
+    /// How quickly noise varies across the grid; higher values give bumpier terrain.
+    pub frequency: f64,
+    /// Maximum column height, in voxels.
+    pub amplitude: f64,
+    /// Number of fractal noise layers summed together (doubling frequency,
+    /// halving amplitude each layer) for added detail. Must be at least `1`;
+    /// `fractal_noise` treats `0` as `1` rather than dividing by zero.
+    pub octaves: u32,
+    /// Seed for the noise field, so worlds are reproducible.
+    pub seed: u32,
+}
+
+impl Default for TerrainConfig {
+    fn default() -> Self {
+        TerrainConfig {
+            grid_size: 14,
+            frequency: 0.1,
+            amplitude: 5.0,
+            octaves: 4,
+            seed: 0,
+        }
+    }
+}
+
+/// Samples `octaves` layers of simplex noise at `(x * frequency, z * frequency)`,
+/// each doubling frequency and halving amplitude, and returns the summed
+/// result normalized to `0.0..1.0`.
+fn fractal_noise(noise: &OpenSimplex, x: i32, z: i32, config: &TerrainConfig) -> f64 {
+    let mut total = 0.0;
+    let mut frequency = config.frequency;
+    let mut amplitude = 1.0;
+    let mut amplitude_sum = 0.0;
+    // `octaves == 0` would otherwise leave amplitude_sum at 0.0 and divide by
+    // zero below, so treat it the same as the minimum of one octave.
+    for _ in 0..config.octaves.max(1) {
+        total += noise.get([x as f64 * frequency, z as f64 * frequency]) * amplitude;
+        amplitude_sum += amplitude;
+        frequency *= 2.0;
+        amplitude *= 0.5;
+    }
+    (total / amplitude_sum + 1.0) / 2.0
+}
+
+/// Generates a stacked-column voxel terrain: for each `(x, z)` on the grid,
+/// a column of boxes is spawned from `y = 0` up to a height quantized from
+/// fractal simplex noise.
+fn generate_terrain(config: &TerrainConfig) -> Vec<PointI32> {
+    let noise = OpenSimplex::new(config.seed);
     let mut points: Vec<PointI32> = vec![];
 
-    let string_map = [
-        "XXXXXXXXXXXXXX",
-        "X  X         X",
-        "X            X",
-        "X  X         X",
-        "X          X X",
-        "X            X",
-        "XXXXXXXXXXXXXX"];
-    let mut z = 0;
-    for line in string_map
-    {
-        let mut x = 0;
-        for char in line.chars()
-        {
-            x += 1;
-            if char == 'X'
-            {
-                points.push(PointI32::new(x,  0, z));
+    for z in 0..config.grid_size {
+        for x in 0..config.grid_size {
+            let height = (fractal_noise(&noise, x, z, config) * config.amplitude).round() as i32;
+            for y in 0..=height {
+                points.push(PointI32::new(x, y, z));
             }
-
-            points.push(PointI32::new(x,  -1, z));
         }
-        z += 1;
     }
 
-    return points;
+    points
+}
+
+fn get_world_points() -> Vec<PointI32> {
+    generate_terrain(&TerrainConfig::default())
 }
 
 fn _get_random_direction() -> PointI32 {
@@ -272,4 +802,143 @@ fn _get_random_direction() -> PointI32 {
 
 fn get_color(point: &PointI32) -> Color {
     return Color::rgb(0.5 + 0.2 * point.x as f32, 0.5 + 0.1 * point.y as f32, 1. - (0.2 * point.z as f32));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ray_intersection_hits_flat_face_straight_on() {
+        let softbox = SoftBox::new(2.0, 2.0, 2.0, 0.2);
+        let transform = GlobalTransform::IDENTITY;
+        // Straight down the local Y axis from above the box, through the
+        // (flat, non-rounded) middle of the top face.
+        let t = softbox
+            .ray_intersection(&transform, Vec3::new(0.0, 5.0, 0.0), Vec3::new(0.0, -1.0, 0.0))
+            .expect("ray should hit the box");
+        assert!((t - 4.0).abs() < 1e-4, "expected t == 4.0, got {t}");
+    }
+
+    #[test]
+    fn ray_intersection_refines_against_rounded_corner() {
+        let softbox = SoftBox::new(2.0, 2.0, 2.0, 0.2);
+        let transform = GlobalTransform::IDENTITY;
+        // Aimed straight at the box's top/right/front corner along its
+        // diagonal, so the flat slab test's hit point falls within
+        // `edge_radius` of two axes at once and refinement must dispatch to
+        // the corner's swept-sphere test rather than the flat face.
+        let corner = Vec3::new(1.0, 1.0, 1.0);
+        let origin = corner * 5.0;
+        let dir = (Vec3::ZERO - origin).normalize();
+        let t = softbox
+            .ray_intersection(&transform, origin, dir)
+            .expect("ray should hit the rounded corner");
+        let hit_point = origin + dir * t;
+        let corner_center = Vec3::new(0.8, 0.8, 0.8);
+        let distance_from_corner_center = (hit_point - corner_center).length();
+        assert!(
+            (distance_from_corner_center - 0.2).abs() < 1e-3,
+            "expected hit point on the corner's radius-0.2 sphere, got distance {distance_from_corner_center}"
+        );
+    }
+
+    #[test]
+    fn ray_intersection_uses_exit_face_when_origin_is_inside() {
+        let softbox = SoftBox::new(2.0, 2.0, 2.0, 0.2);
+        let transform = GlobalTransform::IDENTITY;
+        // Origin starts inside the box (reachable once the camera flies
+        // through it); the hit must be the exit face ahead of the ray, not
+        // the entry face behind it.
+        let t = softbox
+            .ray_intersection(&transform, Vec3::ZERO, Vec3::new(0.0, 1.0, 0.0))
+            .expect("ray should hit the exit face");
+        assert!((t - 1.0).abs() < 1e-4, "expected t == 1.0, got {t}");
+    }
+
+    #[test]
+    fn ray_round_surface_intersection_hits_sphere() {
+        let t = ray_round_surface_intersection(Vec3::new(0.0, 0.0, 5.0), Vec3::new(0.0, 0.0, -1.0), Vec3::ZERO, 1.0, None)
+            .expect("ray should hit the sphere");
+        assert!((t - 4.0).abs() < 1e-4, "expected t == 4.0, got {t}");
+    }
+
+    #[test]
+    fn ray_round_surface_intersection_hits_cylinder_along_free_axis() {
+        // With the y axis free, this is a ray toward an infinite cylinder of
+        // radius 1 running along y, hit from 5 units out along z.
+        let t = ray_round_surface_intersection(
+            Vec3::new(0.0, 3.0, 5.0),
+            Vec3::new(0.0, 0.0, -1.0),
+            Vec3::ZERO,
+            1.0,
+            Some(1),
+        )
+        .expect("ray should hit the cylinder");
+        assert!((t - 4.0).abs() < 1e-4, "expected t == 4.0, got {t}");
+    }
+
+    #[test]
+    fn fractal_noise_stays_in_unit_range() {
+        let noise = OpenSimplex::new(0);
+        let config = TerrainConfig::default();
+        for x in 0..config.grid_size {
+            for z in 0..config.grid_size {
+                let n = fractal_noise(&noise, x, z, &config);
+                assert!((0.0..=1.0).contains(&n), "fractal_noise out of range: {n}");
+            }
+        }
+    }
+
+    #[test]
+    fn fractal_noise_does_not_divide_by_zero_with_no_octaves() {
+        let noise = OpenSimplex::new(0);
+        let config = TerrainConfig { octaves: 0, ..TerrainConfig::default() };
+        let n = fractal_noise(&noise, 0, 0, &config);
+        assert!(n.is_finite(), "fractal_noise produced a non-finite value with octaves == 0");
+    }
+
+    #[test]
+    fn generate_terrain_produces_one_column_per_grid_cell() {
+        let config = TerrainConfig { grid_size: 4, ..TerrainConfig::default() };
+        let points = generate_terrain(&config);
+        let mut columns = std::collections::HashSet::new();
+        for p in &points {
+            assert!(p.y >= 0, "column heights should never go below ground level");
+            columns.insert((p.x, p.z));
+        }
+        assert_eq!(columns.len(), (config.grid_size * config.grid_size) as usize);
+    }
+
+    #[test]
+    fn aabb_matches_box_extents() {
+        let softbox = SoftBox::new(4.0, 2.0, 6.0, 0.5);
+        let (min, max) = softbox.aabb();
+        assert_eq!(min, Vec3::new(-2.0, -1.0, -3.0));
+        assert_eq!(max, Vec3::new(2.0, 1.0, 3.0));
+    }
+
+    #[test]
+    fn bounding_sphere_contains_every_rounded_corner() {
+        let softbox = SoftBox::new(4.0, 2.0, 6.0, 0.5);
+        let (center, radius) = softbox.bounding_sphere();
+        let (min, max) = softbox.aabb();
+        let r = softbox.edge_radius;
+        // Each rounded corner is a sphere of `edge_radius` around an inset
+        // corner center; its farthest point from `center` lies on the line
+        // from `center` through that corner center.
+        for &x in &[min.x + r, max.x - r] {
+            for &y in &[min.y + r, max.y - r] {
+                for &z in &[min.z + r, max.z - r] {
+                    let corner_center = Vec3::new(x, y, z);
+                    let corner = corner_center + (corner_center - center).normalize() * r;
+                    let distance = (corner - center).length();
+                    assert!(
+                        distance <= radius + 1e-4,
+                        "corner {corner:?} at distance {distance} exceeds bounding sphere radius {radius}"
+                    );
+                }
+            }
+        }
+    }
 }
\ No newline at end of file